@@ -0,0 +1,47 @@
+pub mod pdf;
+pub mod svg;
+
+use std::error::Error;
+
+// Reexports
+pub use self::pdf::PdfTarget;
+pub use self::svg::SvgTarget;
+
+/// An RGB color, decoupled from whichever concrete rendering backend draws it.
+#[derive(Debug, Clone, Copy)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub fn rgb(r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b }
+    }
+
+    pub fn gray(v: u8) -> Color {
+        Color { r: v, g: v, b: v }
+    }
+}
+
+/// The drawing primitives a `Report` needs, factored out of `pdf_canvas::Canvas` so the same
+/// report code can feed a PDF, an SVG, or any future backend.
+pub trait RenderTarget {
+    fn set_fill_color(&mut self, color: Color) -> Result<(), Box<Error>>;
+    fn set_stroke_color(&mut self, color: Color) -> Result<(), Box<Error>>;
+    fn set_line_width(&mut self, width: f32) -> Result<(), Box<Error>>;
+
+    fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32) -> Result<(), Box<Error>>;
+    fn rectangle(&mut self, x: f32, y: f32, width: f32, height: f32) -> Result<(), Box<Error>>;
+    fn fill(&mut self) -> Result<(), Box<Error>>;
+    fn stroke(&mut self) -> Result<(), Box<Error>>;
+
+    fn left_text(&mut self, x: f32, y: f32, size: f32, text: &str) -> Result<(), Box<Error>>;
+    fn right_text(&mut self, x: f32, y: f32, size: f32, text: &str) -> Result<(), Box<Error>>;
+    fn center_text(&mut self, x: f32, y: f32, size: f32, text: &str) -> Result<(), Box<Error>>;
+
+    /// Width of `text` at `size`, needed by reports that lay out their own decorations (e.g. an
+    /// underline) around text instead of relying on the backend to center it.
+    fn text_width(&self, size: f32, text: &str) -> f32;
+}
@@ -0,0 +1,71 @@
+use pdf_canvas::graphicsstate::Color as PdfColor;
+use pdf_canvas::{BuiltinFont, Canvas, FontSource};
+
+use std::error::Error;
+
+use super::{Color, RenderTarget};
+
+const FONT: BuiltinFont = BuiltinFont::Helvetica_Bold;
+
+/// Draws onto a live `pdf_canvas::Canvas` page.
+pub struct PdfTarget<'a, 'b: 'a> {
+    pub canvas: &'a mut Canvas<'b>,
+}
+
+impl<'a, 'b> RenderTarget for PdfTarget<'a, 'b> {
+    fn set_fill_color(&mut self, color: Color) -> Result<(), Box<Error>> {
+        self.canvas
+            .set_fill_color(PdfColor::rgb(color.r, color.g, color.b))?;
+        Ok(())
+    }
+
+    fn set_stroke_color(&mut self, color: Color) -> Result<(), Box<Error>> {
+        self.canvas
+            .set_stroke_color(PdfColor::rgb(color.r, color.g, color.b))?;
+        Ok(())
+    }
+
+    fn set_line_width(&mut self, width: f32) -> Result<(), Box<Error>> {
+        self.canvas.set_line_width(width)?;
+        Ok(())
+    }
+
+    fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32) -> Result<(), Box<Error>> {
+        self.canvas.line(x1, y1, x2, y2)?;
+        Ok(())
+    }
+
+    fn rectangle(&mut self, x: f32, y: f32, width: f32, height: f32) -> Result<(), Box<Error>> {
+        self.canvas.rectangle(x, y, width, height)?;
+        Ok(())
+    }
+
+    fn fill(&mut self) -> Result<(), Box<Error>> {
+        self.canvas.fill()?;
+        Ok(())
+    }
+
+    fn stroke(&mut self) -> Result<(), Box<Error>> {
+        self.canvas.stroke()?;
+        Ok(())
+    }
+
+    fn left_text(&mut self, x: f32, y: f32, size: f32, text: &str) -> Result<(), Box<Error>> {
+        self.canvas.left_text(x, y, FONT, size, text)?;
+        Ok(())
+    }
+
+    fn right_text(&mut self, x: f32, y: f32, size: f32, text: &str) -> Result<(), Box<Error>> {
+        self.canvas.right_text(x, y, FONT, size, text)?;
+        Ok(())
+    }
+
+    fn center_text(&mut self, x: f32, y: f32, size: f32, text: &str) -> Result<(), Box<Error>> {
+        self.canvas.center_text(x, y, FONT, size, text)?;
+        Ok(())
+    }
+
+    fn text_width(&self, size: f32, text: &str) -> f32 {
+        FONT.get_width(size, text)
+    }
+}
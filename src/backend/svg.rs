@@ -0,0 +1,170 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use super::{Color, RenderTarget};
+
+/// Accumulates drawing primitives in memory and emits a standalone SVG document on `finish`.
+/// `pdf_canvas` places its origin at the bottom-left with y growing upward; SVG's origin is
+/// top-left with y growing downward, so every coordinate is flipped on the way in.
+pub struct SvgTarget {
+    width: f32,
+    height: f32,
+    elements: Vec<String>,
+    fill_color: Color,
+    stroke_color: Color,
+    line_width: f32,
+    pending_rect: Option<(f32, f32, f32, f32)>,
+    pending_line: Option<(f32, f32, f32, f32)>,
+}
+
+impl SvgTarget {
+    pub fn new(width: f32, height: f32) -> SvgTarget {
+        SvgTarget {
+            width,
+            height,
+            elements: Vec::new(),
+            fill_color: Color::rgb(0, 0, 0),
+            stroke_color: Color::rgb(0, 0, 0),
+            line_width: 1.0,
+            pending_rect: None,
+            pending_line: None,
+        }
+    }
+
+    fn flip_y(&self, y: f32) -> f32 {
+        self.height - y
+    }
+
+    pub fn finish(self, path: &Path) -> Result<(), Box<Error>> {
+        let mut document = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n",
+            w = self.width,
+            h = self.height,
+        );
+        for element in &self.elements {
+            document.push_str(element);
+            document.push('\n');
+        }
+        document.push_str("</svg>\n");
+
+        fs::write(path, document)?;
+        Ok(())
+    }
+}
+
+impl RenderTarget for SvgTarget {
+    fn set_fill_color(&mut self, color: Color) -> Result<(), Box<Error>> {
+        self.fill_color = color;
+        Ok(())
+    }
+
+    fn set_stroke_color(&mut self, color: Color) -> Result<(), Box<Error>> {
+        self.stroke_color = color;
+        Ok(())
+    }
+
+    fn set_line_width(&mut self, width: f32) -> Result<(), Box<Error>> {
+        self.line_width = width;
+        Ok(())
+    }
+
+    fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32) -> Result<(), Box<Error>> {
+        self.pending_line = Some((x1, self.flip_y(y1), x2, self.flip_y(y2)));
+        Ok(())
+    }
+
+    fn rectangle(&mut self, x: f32, y: f32, width: f32, height: f32) -> Result<(), Box<Error>> {
+        // `pdf_canvas` rectangles grow upward from (x, y); flip so they still grow toward the
+        // top of the page once y is inverted.
+        self.pending_rect = Some((x, self.flip_y(y) - height, width, height));
+        Ok(())
+    }
+
+    fn fill(&mut self) -> Result<(), Box<Error>> {
+        if let Some((x, y, width, height)) = self.pending_rect.take() {
+            self.elements.push(format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\" />",
+                x,
+                y,
+                width,
+                height,
+                color_hex(self.fill_color)
+            ));
+        }
+        Ok(())
+    }
+
+    fn stroke(&mut self) -> Result<(), Box<Error>> {
+        if let Some((x1, y1, x2, y2)) = self.pending_line.take() {
+            self.elements.push(format!(
+                "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{}\" stroke-width=\"{}\" />",
+                x1,
+                y1,
+                x2,
+                y2,
+                color_hex(self.stroke_color),
+                self.line_width
+            ));
+        } else if let Some((x, y, width, height)) = self.pending_rect.take() {
+            self.elements.push(format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\" />",
+                x,
+                y,
+                width,
+                height,
+                color_hex(self.stroke_color),
+                self.line_width
+            ));
+        }
+        Ok(())
+    }
+
+    fn left_text(&mut self, x: f32, y: f32, size: f32, text: &str) -> Result<(), Box<Error>> {
+        self.elements
+            .push(self.text_element(x, y, size, "start", text));
+        Ok(())
+    }
+
+    fn right_text(&mut self, x: f32, y: f32, size: f32, text: &str) -> Result<(), Box<Error>> {
+        self.elements
+            .push(self.text_element(x, y, size, "end", text));
+        Ok(())
+    }
+
+    fn center_text(&mut self, x: f32, y: f32, size: f32, text: &str) -> Result<(), Box<Error>> {
+        self.elements
+            .push(self.text_element(x, y, size, "middle", text));
+        Ok(())
+    }
+
+    fn text_width(&self, size: f32, text: &str) -> f32 {
+        // No font metrics available without a real font rasterizer; Helvetica Bold digits and
+        // caps run close to 0.6em wide, which is good enough for laying out a title underline.
+        text.chars().count() as f32 * size * 0.6
+    }
+}
+
+impl SvgTarget {
+    fn text_element(&self, x: f32, y: f32, size: f32, anchor: &str, text: &str) -> String {
+        format!(
+            "<text x=\"{}\" y=\"{}\" font-size=\"{}\" font-family=\"Helvetica, sans-serif\" font-weight=\"bold\" text-anchor=\"{}\" fill=\"{}\">{}</text>",
+            x,
+            self.flip_y(y),
+            size,
+            anchor,
+            color_hex(self.fill_color),
+            escape_xml(text)
+        )
+    }
+}
+
+fn color_hex(color: Color) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b)
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -0,0 +1,39 @@
+use std::collections::BTreeSet;
+
+use interval::Interval;
+
+/// Splits a comma-separated config value (e.g. `timeskwire.report.include`) into a tag set,
+/// trimming whitespace and dropping empty entries.
+pub fn parse_tag_list(raw: &str) -> BTreeSet<String> {
+    raw.split(',')
+        .map(|tag| tag.trim())
+        .filter(|tag| !tag.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Keeps only intervals whose tags contain every `include` tag (when `include` is non-empty)
+/// and none of the `exclude` tags.
+pub fn filter_intervals(
+    intervals: Vec<Interval>,
+    include: &BTreeSet<String>,
+    exclude: &BTreeSet<String>,
+) -> Vec<Interval> {
+    intervals
+        .into_iter()
+        .filter(|interval| {
+            (include.is_empty() || include.is_subset(&interval.tags))
+                && exclude.is_disjoint(&interval.tags)
+        })
+        .collect()
+}
+
+/// Collapses a tag set down to the tags named in `group_by`, so aggregation buckets by the
+/// chosen dimension instead of the whole set. An empty `group_by` leaves tags untouched.
+pub fn group_tags(tags: &BTreeSet<String>, group_by: &BTreeSet<String>) -> BTreeSet<String> {
+    if group_by.is_empty() {
+        tags.clone()
+    } else {
+        tags.intersection(group_by).cloned().collect()
+    }
+}
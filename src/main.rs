@@ -5,18 +5,23 @@ extern crate libc;
 extern crate palette;
 extern crate pdf_canvas;
 extern crate serde_json;
+extern crate toml;
 
 #[macro_use]
 extern crate log;
 #[macro_use]
 extern crate serde_derive;
 
+mod backend;
+mod filter;
 mod interval;
 mod reports;
+mod theme;
 mod util;
 
 use chrono::{Local, TimeZone, Utc};
 use docopt::Docopt;
+use pdf_canvas::Pdf;
 use serde_json::Value;
 
 use std::collections::{BTreeSet, HashMap};
@@ -26,11 +31,14 @@ use std::fs;
 use std::io;
 use std::io::{BufReader, Read, Write};
 use std::os::unix;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process;
 
+use backend::{PdfTarget, SvgTarget};
+use filter::{filter_intervals, group_tags, parse_tag_list};
 use interval::Interval;
-use reports::{DefaultReport, Report};
+use reports::{DailyBreakdownReport, DefaultReport, Report, TimelineReport};
+use theme::Theme;
 
 const USAGE: &'static str = "
 TimeSkwire - a PDF render extension for TimeWarrior.
@@ -94,6 +102,21 @@ fn main() {
 
     let (config, intervals) = parse_input(BufReader::new(io::stdin())).unwrap();
 
+    let include = config
+        .get("timeskwire.report.include")
+        .map(|raw| parse_tag_list(raw))
+        .unwrap_or_default();
+    let exclude = config
+        .get("timeskwire.report.exclude")
+        .map(|raw| parse_tag_list(raw))
+        .unwrap_or_default();
+    let group_by = config
+        .get("timeskwire.report.group_by")
+        .map(|raw| parse_tag_list(raw))
+        .unwrap_or_default();
+
+    let intervals = filter_intervals(intervals, &include, &exclude);
+
     println!(
         "TimeWarrior version {}",
         config
@@ -112,28 +135,77 @@ fn main() {
         });
 
     let report: Box<Report> = match report_kind.as_str() {
+        "timeline" => Box::new(TimelineReport {}),
+        "daily" => Box::new(DailyBreakdownReport {}),
         "default" => Box::new(DefaultReport {}),
         _ => Box::new(DefaultReport {}),
     };
 
-    let doc = report
-        .render(
-            &config,
-            &intervals,
-            match config.get("timeskwire.report.filename") {
-                Some(name) => &name,
-                None => {
-                    info!(
-                        "No report filename defined, falling back to {}",
-                        DEFAULT_REPORT_FILENAME
-                    );
-                    DEFAULT_REPORT_FILENAME
-                }
-            },
-        )
-        .unwrap();
-
-    doc.finish().unwrap();
+    let theme_path = env::var("TIMESKWIRE_THEME")
+        .ok()
+        .or_else(|| config.get("timeskwire.report.theme").cloned());
+
+    let theme = theme_path.map(|path| {
+        Theme::load(PathBuf::from(&path).as_path()).unwrap_or_else(|e| {
+            writeln!(
+                io::stderr(),
+                "timeskwire: theme: Could not load {:?}: {}",
+                path,
+                e.to_string()
+            ).unwrap();
+            process::exit(libc::EXIT_FAILURE);
+        })
+    });
+
+    let report_filename: String = match config.get("timeskwire.report.filename") {
+        Some(name) => name.to_owned(),
+        None => {
+            info!(
+                "No report filename defined, falling back to {}",
+                DEFAULT_REPORT_FILENAME
+            );
+            String::from(DEFAULT_REPORT_FILENAME)
+        }
+    };
+
+    // Pick the backend from an explicit config key, falling back to the report filename's
+    // extension so `report.svg` just works without extra configuration.
+    let report_format = config
+        .get("timeskwire.report.format")
+        .cloned()
+        .unwrap_or_else(|| {
+            Path::new(&report_filename)
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .unwrap_or("pdf")
+                .to_owned()
+        });
+
+    let page_dim = (180.0, 240.0);
+
+    let group_by_fn = |tags: &BTreeSet<String>| group_tags(tags, &group_by);
+
+    match report_format.as_str() {
+        "svg" => {
+            let mut target = SvgTarget::new(page_dim.0, page_dim.1);
+            report
+                .render(&config, &intervals, theme.as_ref(), &group_by_fn, &mut target)
+                .unwrap();
+            target.finish(Path::new(&report_filename)).unwrap();
+        }
+        _ => {
+            let mut document = Pdf::create(&report_filename).unwrap();
+            document
+                .render_page(page_dim.0, page_dim.1, |canvas| {
+                    let mut target = PdfTarget { canvas };
+                    report
+                        .render(&config, &intervals, theme.as_ref(), &group_by_fn, &mut target)
+                        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
+                })
+                .unwrap();
+            document.finish().unwrap();
+        }
+    }
 }
 
 fn init(extension_path: &mut PathBuf, force: bool) -> Result<(), Box<Error>> {
@@ -0,0 +1,229 @@
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone, Utc};
+
+use std::collections::{BTreeSet, HashMap};
+use std::error::Error;
+
+use super::Report;
+use backend::{Color, RenderTarget};
+use interval::Interval;
+use theme::{self, Theme};
+
+/// Buckets intervals by calendar day and draws one stacked bar per day, so the distribution of
+/// effort over time is visible instead of a single aggregate bar.
+#[derive(Default)]
+pub struct DailyBreakdownReport;
+
+impl Report for DailyBreakdownReport {
+    fn render(
+        &self,
+        config: &HashMap<String, String>,
+        intervals: &Vec<Interval>,
+        theme: Option<&Theme>,
+        group_by: &Fn(&BTreeSet<String>) -> BTreeSet<String>,
+        target: &mut RenderTarget,
+    ) -> Result<(), Box<Error>> {
+        debug!(
+            "Report span (Strings): {:?} - {:?}",
+            config["temp.report.start"], config["temp.report.end"]
+        );
+
+        let format = "%Y%m%dT%H%M%SZ";
+        let start_utc = Utc
+            .datetime_from_str(&config["temp.report.start"], format)?
+            .naive_utc();
+        let end_utc = match config["temp.report.end"].as_str() {
+            "" => Utc::now().naive_utc(),
+            val => Utc.datetime_from_str(val, format)?.naive_utc(),
+        };
+
+        let window_start = Local.from_utc_datetime(&start_utc);
+        let window_end = Local.from_utc_datetime(&end_utc);
+
+        println!("Report start:\t{}", window_start.to_rfc2822());
+        println!("Report end:\t{}", window_end.to_rfc2822());
+
+        // Split each interval across midnight boundaries, clipped to the report window, so a
+        // span crossing a day change is apportioned into both days and daily totals still sum
+        // to the grand total.
+        let mut buckets: HashMap<NaiveDate, HashMap<BTreeSet<String>, Duration>> = HashMap::new();
+        for interval in intervals {
+            let clipped_start = if interval.start < window_start {
+                window_start
+            } else {
+                interval.start
+            };
+            let clipped_end = if interval.end > window_end {
+                window_end
+            } else {
+                interval.end
+            };
+            if clipped_end <= clipped_start {
+                continue;
+            }
+
+            let tag_set = group_by(&interval.tags);
+
+            for (day, duration) in split_by_day(clipped_start, clipped_end) {
+                let day_bucket = buckets.entry(day).or_insert_with(HashMap::new);
+                let entry = day_bucket
+                    .entry(tag_set.clone())
+                    .or_insert_with(|| Duration::seconds(0));
+                *entry = *entry + duration;
+            }
+        }
+
+        // Every calendar day in the window gets a slot, even with no logged time, to keep the
+        // x-axis continuous.
+        let mut days: Vec<NaiveDate> = Vec::new();
+        let mut day_cursor = window_start.naive_local().date();
+        let end_date = window_end.naive_local().date();
+        while day_cursor <= end_date {
+            days.push(day_cursor);
+            day_cursor = day_cursor + Duration::days(1);
+        }
+
+        // Stable color per unique tag set, same scheme as the other reports.
+        let mut unique_tag_sets: HashMap<BTreeSet<String>, Duration> = HashMap::new();
+        for day_bucket in buckets.values() {
+            for (tag_set, duration) in day_bucket {
+                let entry = unique_tag_sets
+                    .entry(tag_set.clone())
+                    .or_insert_with(|| Duration::seconds(0));
+                *entry = *entry + *duration;
+            }
+        }
+
+        let mut sorted_tag_sets: Vec<_> = unique_tag_sets.keys().collect();
+        sorted_tag_sets.sort_unstable();
+
+        let colors = theme::assign_colors(&unique_tag_sets, theme);
+
+        let busiest_total = days
+            .iter()
+            .map(|day| day_total(&buckets, day))
+            .max_by_key(|duration| duration.num_seconds())
+            .unwrap_or_else(|| Duration::seconds(0));
+
+        let page_dim = (180.0, 240.0);
+        let margin = 10.0;
+
+        let title = &format!(
+            "{} - {}",
+            window_start.format("%Y-%m-%d"),
+            window_end.format("%Y-%m-%d")
+        );
+        let title_font_size = 10.0;
+        let title_y = page_dim.1 - 20.0;
+
+        target.center_text(90.0, title_y, title_font_size, title)?;
+        target.left_text(margin, title_y - 15.0, 8.0, "Time spent per day")?;
+
+        // Compact legend: one color square + tag set per line.
+        let legend_initial_y = title_y - 25.0;
+        let mut legend_offset = 0.0;
+        for tag_set in &sorted_tag_sets {
+            let color = colors[*tag_set];
+            target.set_fill_color(Color::rgb(color.0, color.1, color.2))?;
+            target.rectangle(margin, legend_initial_y - legend_offset, 3.0, 3.0)?;
+            target.fill()?;
+
+            target.set_fill_color(Color::rgb(0, 0, 0))?;
+            target.left_text(
+                margin * 2.0,
+                legend_initial_y - legend_offset,
+                4.0,
+                &format!("{:?}", tag_set),
+            )?;
+            legend_offset += 5.0;
+        }
+
+        // Bar chart: one stacked bar per day, scaled to the busiest day's total.
+        let chart_x = margin;
+        let chart_width = page_dim.0 - 2.0 * margin;
+        let chart_bottom = legend_initial_y - (legend_offset + 15.0);
+        let chart_height = 100.0;
+        let chart_top = chart_bottom + chart_height;
+
+        target.set_stroke_color(Color::gray(0))?;
+        target.set_line_width(0.3)?;
+        target.line(chart_x, chart_bottom, chart_x + chart_width, chart_bottom)?;
+        target.stroke()?;
+
+        let busiest_seconds = busiest_total.num_seconds().max(1) as f32;
+        let bar_slot_width = chart_width / days.len() as f32;
+        let bar_width = bar_slot_width * 0.7;
+
+        for (day_idx, day) in days.iter().enumerate() {
+            let bar_x = chart_x + bar_slot_width * day_idx as f32 + (bar_slot_width - bar_width) / 2.0;
+
+            target.left_text(
+                bar_x,
+                chart_bottom - 5.0,
+                3.0,
+                &day.format("%m-%d").to_string(),
+            )?;
+
+            let mut segments: Vec<(&BTreeSet<String>, &Duration)> = match buckets.get(day) {
+                Some(day_bucket) => day_bucket.iter().collect(),
+                None => continue,
+            };
+            segments.sort_unstable_by_key(|(tag_set, _duration)| (*tag_set).clone());
+
+            let mut segment_y = chart_bottom;
+            for (tag_set, duration) in segments {
+                let segment_height = chart_height * (duration.num_seconds() as f32 / busiest_seconds);
+                let color = colors.get(tag_set).unwrap_or(&(128, 128, 128));
+
+                target.set_fill_color(Color::rgb(color.0, color.1, color.2))?;
+                target.rectangle(bar_x, segment_y, bar_width, segment_height)?;
+                target.fill()?;
+
+                segment_y += segment_height;
+            }
+        }
+
+        // y-axis label for the busiest day, so the scale is legible.
+        target.set_fill_color(Color::rgb(0, 0, 0))?;
+        target.right_text(
+            chart_x - 1.0,
+            chart_top - 3.0,
+            3.0,
+            &format!("{}h", busiest_total.num_hours()),
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Splits `[start, end)` at midnight boundaries, returning how much of the span falls on each
+/// calendar day.
+fn split_by_day(start: DateTime<Local>, end: DateTime<Local>) -> Vec<(NaiveDate, Duration)> {
+    let mut segments = Vec::new();
+    let mut cursor = start;
+
+    while cursor < end {
+        let next_midnight = (cursor.date() + Duration::days(1)).and_hms(0, 0, 0);
+        let segment_end = if next_midnight < end { next_midnight } else { end };
+
+        segments.push((
+            cursor.naive_local().date(),
+            segment_end.signed_duration_since(cursor),
+        ));
+
+        cursor = segment_end;
+    }
+
+    segments
+}
+
+fn day_total(
+    buckets: &HashMap<NaiveDate, HashMap<BTreeSet<String>, Duration>>,
+    day: &NaiveDate,
+) -> Duration {
+    match buckets.get(day) {
+        Some(day_bucket) => day_bucket
+            .values()
+            .fold(Duration::seconds(0), |acc, duration| acc + *duration),
+        None => Duration::seconds(0),
+    }
+}
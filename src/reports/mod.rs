@@ -1,20 +1,26 @@
+pub mod daily;
 pub mod default;
+pub mod timeline;
 
-use pdf_canvas::Pdf;
-
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::error::Error;
 
+use backend::RenderTarget;
 use interval::Interval;
+use theme::Theme;
 
 // Reexports
+pub use self::daily::DailyBreakdownReport;
 pub use self::default::DefaultReport;
+pub use self::timeline::TimelineReport;
 
 pub trait Report {
     fn render(
         &self,
         config: &HashMap<String, String>,
         intervals: &Vec<Interval>,
-        report_filename: &str,
-    ) -> Result<Pdf, Box<Error>>;
+        theme: Option<&Theme>,
+        group_by: &Fn(&BTreeSet<String>) -> BTreeSet<String>,
+        target: &mut RenderTarget,
+    ) -> Result<(), Box<Error>>;
 }
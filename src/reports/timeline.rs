@@ -0,0 +1,208 @@
+use chrono::{DateTime, Duration, Local, TimeZone, Utc};
+
+use std::collections::{BTreeSet, HashMap};
+use std::error::Error;
+
+use super::Report;
+use backend::{Color, RenderTarget};
+use interval::Interval;
+use theme::{self, Theme};
+
+/// Renders intervals on a horizontal time axis instead of aggregating them into one summary bar,
+/// so the report shows *when* work happened rather than just how much.
+#[derive(Default)]
+pub struct TimelineReport;
+
+impl Report for TimelineReport {
+    fn render(
+        &self,
+        config: &HashMap<String, String>,
+        intervals: &Vec<Interval>,
+        theme: Option<&Theme>,
+        group_by: &Fn(&BTreeSet<String>) -> BTreeSet<String>,
+        target: &mut RenderTarget,
+    ) -> Result<(), Box<Error>> {
+        debug!(
+            "Report span (Strings): {:?} - {:?}",
+            config["temp.report.start"], config["temp.report.end"]
+        );
+
+        let format = "%Y%m%dT%H%M%SZ";
+        let start_utc = Utc
+            .datetime_from_str(&config["temp.report.start"], format)?
+            .naive_utc();
+        let end_utc = match config["temp.report.end"].as_str() {
+            "" => Utc::now().naive_utc(),
+            val => Utc.datetime_from_str(val, format)?.naive_utc(),
+        };
+
+        let window_start = Local.from_utc_datetime(&start_utc);
+        let window_end = Local.from_utc_datetime(&end_utc);
+
+        println!("Report start:\t{}", window_start.to_rfc2822());
+        println!("Report end:\t{}", window_end.to_rfc2822());
+
+        let window_seconds = window_end
+            .signed_duration_since(window_start)
+            .num_seconds() as f32;
+
+        // Tags narrowed by the active grouping, computed once per interval and reused for both
+        // color assignment and row placement below.
+        let grouped_tags: Vec<BTreeSet<String>> =
+            intervals.iter().map(|interval| group_by(&interval.tags)).collect();
+
+        // Assign each unique tag set a stable color the same way DefaultReport does, so the two
+        // report kinds read consistently.
+        let mut unique_tag_sets: HashMap<BTreeSet<String>, Duration> = HashMap::new();
+        for (interval, tag_set) in intervals.iter().zip(&grouped_tags) {
+            let entry = unique_tag_sets
+                .entry(tag_set.clone())
+                .or_insert(Duration::seconds(0));
+            *entry = *entry + interval.to_duration();
+        }
+
+        let colors = theme::assign_colors(&unique_tag_sets, theme);
+
+        // One row per primary (first, alphabetically) tag of the grouped tag set; intervals with
+        // no tags (after grouping) share a row.
+        let mut rows: Vec<String> = {
+            let mut primary_tags: BTreeSet<String> = BTreeSet::new();
+            for tag_set in &grouped_tags {
+                primary_tags.insert(
+                    tag_set
+                        .iter()
+                        .next()
+                        .cloned()
+                        .unwrap_or_else(|| String::from("(untagged)")),
+                );
+            }
+            primary_tags.into_iter().collect()
+        };
+        rows.sort();
+
+        let page_dim = (180.0, 240.0);
+        let margin = 10.0;
+
+        let title = &format!(
+            "{} - {}",
+            window_start.format("%Y-%m-%d"),
+            window_end.format("%Y-%m-%d")
+        );
+        let title_font_size = 10.0;
+        let title_y = page_dim.1 - 20.0;
+
+        target.center_text(90.0, title_y, title_font_size, title)?;
+        target.left_text(margin, title_y - 15.0, 8.0, "Timeline")?;
+
+        let chart_x = margin + 25.0;
+        let chart_width = page_dim.0 - chart_x - margin;
+        let chart_top = title_y - 30.0;
+        let row_height = 10.0;
+        let lane_height = row_height / 2.0;
+
+        // Day gridlines + date labels at day boundaries within the window.
+        target.set_stroke_color(Color::gray(200))?;
+        target.set_line_width(0.2)?;
+
+        let mut day_cursor = window_start.date().and_hms(0, 0, 0);
+        while day_cursor < window_end {
+            if day_cursor >= window_start {
+                let ratio = day_cursor
+                    .signed_duration_since(window_start)
+                    .num_seconds() as f32
+                    / window_seconds;
+                let x = chart_x + chart_width * ratio;
+
+                target.line(x, chart_top, x, chart_top - row_height * rows.len() as f32)?;
+                target.stroke()?;
+                target.left_text(
+                    x + 1.0,
+                    chart_top + 3.0,
+                    3.5,
+                    &day_cursor.format("%m-%d").to_string(),
+                )?;
+            }
+            day_cursor = day_cursor + Duration::days(1);
+        }
+
+        // One row per primary tag; overlapping intervals within a row are pushed into
+        // additional lanes so they don't cover each other. A row is two lanes tall: a third or
+        // later lane is drawn below the row, over the area of the row beneath it, whose bars
+        // can then cover it.
+        for (row_idx, row_tag) in rows.iter().enumerate() {
+            let row_y = chart_top - row_height * (row_idx as f32 + 1.0);
+
+            target.set_fill_color(Color::rgb(0, 0, 0))?;
+            target.right_text(chart_x - 2.0, row_y + lane_height / 2.0, 4.0, row_tag)?;
+
+            let mut lane_ends: Vec<DateTime<Local>> = Vec::new();
+
+            let mut row_intervals: Vec<(&Interval, &BTreeSet<String>)> = intervals
+                .iter()
+                .zip(&grouped_tags)
+                .filter(|(_interval, tag_set)| {
+                    let primary = tag_set
+                        .iter()
+                        .next()
+                        .cloned()
+                        .unwrap_or_else(|| String::from("(untagged)"));
+                    &primary == row_tag
+                })
+                .collect();
+            row_intervals.sort_unstable_by_key(|(interval, _tag_set)| interval.start);
+
+            for (interval, tag_set) in row_intervals {
+                // Clip to the report window.
+                let clipped_start = if interval.start < window_start {
+                    window_start
+                } else {
+                    interval.start
+                };
+                let clipped_end = if interval.end > window_end {
+                    window_end
+                } else {
+                    interval.end
+                };
+                if clipped_end <= clipped_start {
+                    continue;
+                }
+
+                // Find the first lane whose last interval ends before this one starts, else
+                // open a new lane.
+                let lane = match lane_ends
+                    .iter()
+                    .position(|lane_end| *lane_end <= clipped_start)
+                {
+                    Some(idx) => {
+                        lane_ends[idx] = clipped_end;
+                        idx
+                    }
+                    None => {
+                        lane_ends.push(clipped_end);
+                        lane_ends.len() - 1
+                    }
+                };
+
+                let start_ratio = clipped_start
+                    .signed_duration_since(window_start)
+                    .num_seconds() as f32
+                    / window_seconds;
+                let end_ratio = clipped_end
+                    .signed_duration_since(window_start)
+                    .num_seconds() as f32
+                    / window_seconds;
+
+                let x0 = chart_x + chart_width * start_ratio;
+                let x1 = chart_x + chart_width * end_ratio;
+                let lane_y = row_y + row_height - lane_height * (lane as f32 + 1.0);
+
+                let color = colors.get(tag_set).unwrap_or(&(128, 128, 128));
+                target.set_fill_color(Color::rgb(color.0, color.1, color.2))?;
+                target.rectangle(x0, lane_y, (x1 - x0).max(0.2), lane_height - 0.5)?;
+                target.fill()?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+extern crate toml;
+
+use chrono::Duration;
+use palette::named;
+use palette::rgb::Rgba;
+use palette::Hsv;
+use palette::RgbHue;
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::error::Error;
+use std::f32::consts;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Default, Deserialize)]
+struct ThemeFile {
+    name: Option<String>,
+    base: Option<String>,
+    theme: Option<HashMap<String, String>>,
+}
+
+/// A resolved mapping from tag (or tag-set) names to fixed colors, built by merging a theme
+/// file's `base` chain parent-first so children can override a subset of their parent's entries.
+#[derive(Debug, Default)]
+pub struct Theme {
+    colors: HashMap<String, (u8, u8, u8)>,
+}
+
+impl Theme {
+    pub fn load(path: &Path) -> Result<Theme, Box<Error>> {
+        Theme::load_with_visited(path, &mut HashSet::new())
+    }
+
+    fn load_with_visited(path: &Path, visited: &mut HashSet<PathBuf>) -> Result<Theme, Box<Error>> {
+        let canonical_path = path.canonicalize()?;
+        if !visited.insert(canonical_path) {
+            return Err(From::from(format!(
+                "Circular theme inheritance detected at {:?}",
+                path
+            )));
+        }
+
+        let raw = fs::read_to_string(path)?;
+        let parsed: ThemeFile = toml::from_str(&raw)?;
+
+        if let Some(ref declared_name) = parsed.name {
+            let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+            if declared_name != stem {
+                warn!(
+                    "Theme file {:?} declares name {:?} which does not match its filename",
+                    path, declared_name
+                );
+            }
+        }
+
+        let mut colors = HashMap::new();
+
+        // Resolve the base theme first so this file's own mappings win on conflicts.
+        if let Some(ref base) = parsed.base {
+            let base_path = path.with_file_name(base);
+            let base_theme = Theme::load_with_visited(&base_path, visited)?;
+            colors.extend(base_theme.colors);
+        }
+
+        if let Some(entries) = parsed.theme {
+            for (key, value) in entries {
+                colors.insert(normalize_key(&key), parse_color(&value)?);
+            }
+        }
+
+        Ok(Theme { colors })
+    }
+
+    /// Looks up the fixed color for a whole tag set (or a single tag, which is just a one-element
+    /// set). Returns `None` when the theme has no explicit mapping, leaving the caller free to
+    /// fall back to auto-assigned colors.
+    pub fn color_for_tags(&self, tags: &BTreeSet<String>) -> Option<(u8, u8, u8)> {
+        let key = tags.iter().cloned().collect::<Vec<_>>().join(",");
+        self.colors.get(&key).cloned()
+    }
+}
+
+/// Assigns a stable color to each tag set: tag sets with an explicit theme mapping get that
+/// color; the rest are spread evenly around the HSV hue circle in sorted order for determinism.
+/// Themed tag sets don't consume a hue slot, so they don't throw off the spacing of the rest.
+pub fn assign_colors(
+    tag_sets: &HashMap<BTreeSet<String>, Duration>,
+    theme: Option<&Theme>,
+) -> HashMap<BTreeSet<String>, (u8, u8, u8)> {
+    let untagged_count = tag_sets
+        .keys()
+        .filter(|tag_set| theme.and_then(|t| t.color_for_tags(tag_set)).is_none())
+        .count() as f32;
+    let colorspace_increment = if untagged_count > 0.0 {
+        2.0 * consts::PI / untagged_count
+    } else {
+        0.0
+    };
+
+    let mut sorted_tag_sets: Vec<_> = tag_sets.keys().collect();
+    sorted_tag_sets.sort_unstable();
+
+    let mut current_color_radians = 0.0;
+    let mut colors = HashMap::new();
+    for tag_set in sorted_tag_sets {
+        let color_tuple = match theme.and_then(|t| t.color_for_tags(tag_set)) {
+            Some(fixed_color) => fixed_color,
+            None => {
+                let color_hsv = Hsv::new(RgbHue::from_radians(current_color_radians), 1.0, 0.75);
+                let color_rgb: Rgba = color_hsv.into();
+                let color_components: (f32, f32, f32, f32) = color_rgb.into_components();
+                current_color_radians += colorspace_increment;
+                (
+                    (color_components.0 * 256.0) as u8,
+                    (color_components.1 * 256.0) as u8,
+                    (color_components.2 * 256.0) as u8,
+                )
+            }
+        };
+
+        colors.insert(tag_set.clone(), color_tuple);
+    }
+
+    colors
+}
+
+fn normalize_key(key: &str) -> String {
+    let mut tags: Vec<&str> = key.split(',').map(|tag| tag.trim()).collect();
+    tags.sort_unstable();
+    tags.join(",")
+}
+
+fn parse_color(value: &str) -> Result<(u8, u8, u8), Box<Error>> {
+    if value.starts_with('#') {
+        let hex = &value[1..];
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(From::from(format!(
+                "Invalid theme color {:?}: expected exactly 6 hex digits",
+                value
+            )));
+        }
+
+        let r = u8::from_str_radix(&hex[0..2], 16)?;
+        let g = u8::from_str_radix(&hex[2..4], 16)?;
+        let b = u8::from_str_radix(&hex[4..6], 16)?;
+
+        return Ok((r, g, b));
+    }
+
+    named_color(value).ok_or_else(|| {
+        From::from(format!(
+            "Invalid theme color {:?}: not a #RRGGBB hex string or a known palette name",
+            value
+        ))
+    })
+}
+
+fn named_color(name: &str) -> Option<(u8, u8, u8)> {
+    let rgb = match name.to_lowercase().as_str() {
+        "red" => named::RED,
+        "green" => named::GREEN,
+        "blue" => named::BLUE,
+        "black" => named::BLACK,
+        "white" => named::WHITE,
+        "yellow" => named::YELLOW,
+        "cyan" => named::CYAN,
+        "magenta" => named::MAGENTA,
+        "orange" => named::ORANGE,
+        "purple" => named::PURPLE,
+        "gray" | "grey" => named::GRAY,
+        _ => return None,
+    };
+
+    Some((rgb.red, rgb.green, rgb.blue))
+}
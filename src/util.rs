@@ -10,3 +10,38 @@ pub fn format_hms(d: &Duration) -> String {
     let s = tmp.num_seconds();
     format!("{}:{:02}:{:02}", h, m, s)
 }
+
+/// Formats a duration as a single coarse natural-language magnitude, e.g. "3 Days" or
+/// "1 Minute", picking the largest non-zero unit instead of breaking it down fully. 52 weeks
+/// fold into "1 Year". Always shows exactly one unit, even "0 Seconds" for sub-second durations.
+pub fn format_human(d: &Duration) -> String {
+    let weeks = d.num_weeks();
+    if weeks >= 52 {
+        return pluralize(weeks / 52, "Year");
+    }
+
+    let days = d.num_days();
+    if days > 0 {
+        return pluralize(days, "Day");
+    }
+
+    let hours = d.num_hours();
+    if hours > 0 {
+        return pluralize(hours, "Hour");
+    }
+
+    let minutes = d.num_minutes();
+    if minutes > 0 {
+        return pluralize(minutes, "Minute");
+    }
+
+    pluralize(d.num_seconds(), "Second")
+}
+
+fn pluralize(n: i64, unit: &str) -> String {
+    if n == 1 {
+        format!("1 {}", unit)
+    } else {
+        format!("{} {}s", n, unit)
+    }
+}